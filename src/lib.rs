@@ -1,9 +1,9 @@
 use bevy::{
     ecs::{
-        component::StorageType,
+        component::{StorageType, Tick},
         query::{QueryData, QueryFilter},
-        system::{SystemParam, SystemParamItem, SystemState},
-        world::DeferredWorld,
+        system::{SystemMeta, SystemParam, SystemParamItem, SystemState},
+        world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld},
     },
     prelude::*,
 };
@@ -19,7 +19,14 @@ pub trait ReactiveQueryData<F: QueryFilter>: QueryData + Sized {
 
     fn init(world: &mut World) -> <Self as ReactiveQueryData<F>>::State;
 
-    fn is_changed(world: DeferredWorld, state: &mut <Self as ReactiveQueryData<F>>::State) -> bool;
+    /// `last_run` is the tick of the reaction's previous run, so an impl can
+    /// check "changed since *this reaction* last ran" instead of relying on
+    /// the world's ambient change tick.
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveQueryData<F>>::State,
+        last_run: Tick,
+    ) -> bool;
 
     fn get<'w, 's>(
         world: &'w mut DeferredWorld<'w>,
@@ -33,7 +40,7 @@ where
     T: Component,
 {
     type State = SystemState<(
-        Query<'static, 'static, (), (Changed<T>, F)>,
+        Query<'static, 'static, Ref<'static, T>, F>,
         Query<'static, 'static, &'static T, F>,
     )>;
 
@@ -44,25 +51,103 @@ where
     fn is_changed<'w>(
         world: DeferredWorld,
         state: &mut <Self as ReactiveQueryData<F>>::State,
+        last_run: Tick,
     ) -> bool {
-        !state.get(&world).0.is_empty()
+        // Compare each matched item's own change tick against this
+        // reaction's `last_run`, same as the `Res`-family impls, instead
+        // of leaning on the `SystemState`'s implicit last-queried tick.
+        let this_run = Tick::new(world.change_tick());
+        state
+            .get(&world)
+            .0
+            .iter()
+            .any(|item| item.last_changed().is_newer_than(last_run, this_run))
     }
 
     fn get<'w, 's>(
         world: &'w mut DeferredWorld<'w>,
         state: &'s mut <Self as ReactiveQueryData<F>>::State,
     ) -> Query<'w, 's, Self, F> {
-        // TODO verify safety
+        // SAFETY: only reinterprets the `'static` lifetimes `SystemState`
+        // was created with as the real `'w`/`'s` borrows of `world`/`state`
+        // that produced this `Query`; the underlying value is unchanged.
         unsafe { mem::transmute(state.get(&world).1) }
     }
 }
 
+// `F` is bound by `QueryFilter`, which (unlike the pre-#6008 `WorldQuery`)
+// is always read-only, so it can't smuggle mutable access alongside the
+// `&mut T` below. Note that two `&mut` data params conflict with each
+// other the same way two overlapping `Query`s do; combine them through
+// [`ReactiveParamSet`] rather than a plain tuple.
+// Unlike the `&T` impl above, the change probe and the data query can't
+// share a single `SystemState`: the `Ref<T>` probe is a read of `T` in Bevy's
+// conflict checker, and pairing it with `&mut T` as two params of the
+// *same* system is exactly the conflicting-access case `SystemState::new`
+// panics on. Keeping them as two independent `SystemState`s (the same
+// trick `ReactiveParamSet` uses) sidesteps the conflict entirely, since
+// each is only ever checked against its own single access.
+impl<F, T> ReactiveQueryData<F> for &mut T
+where
+    F: QueryFilter + 'static,
+    T: Component,
+{
+    type State = (
+        SystemState<Query<'static, 'static, Ref<'static, T>, F>>,
+        SystemState<Query<'static, 'static, &'static mut T, F>>,
+    );
+
+    fn init(world: &mut World) -> <Self as ReactiveQueryData<F>>::State {
+        (SystemState::new(world), SystemState::new(world))
+    }
+
+    fn is_changed<'w>(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveQueryData<F>>::State,
+        last_run: Tick,
+    ) -> bool {
+        // Compare each matched item's own change tick against this
+        // reaction's `last_run`, same as the `Res`-family impls, instead
+        // of leaning on the `SystemState`'s implicit last-queried tick.
+        let this_run = Tick::new(world.change_tick());
+        state
+            .0
+            .get(&world)
+            .iter()
+            .any(|item| item.last_changed().is_newer_than(last_run, this_run))
+    }
+
+    fn get<'w, 's>(
+        world: &'w mut DeferredWorld<'w>,
+        state: &'s mut <Self as ReactiveQueryData<F>>::State,
+    ) -> Query<'w, 's, Self, F> {
+        // `SystemState::get` requires `Param: ReadOnlySystemParam`, which a
+        // `Query<&mut T, F>` is not, so fetch through the same
+        // `get_unchecked_manual` escape hatch Bevy's own `Query::get_mut`
+        // machinery uses for mutable params.
+        //
+        // SAFETY: the reaction scheduler guarantees exclusive world access
+        // for the duration of a single reaction run, and `state.1` is never
+        // fetched concurrently with anything else that could alias `T`
+        // (conflicting members must instead go through `ReactiveParamSet`).
+        let cell = world.as_unsafe_world_cell();
+        unsafe { mem::transmute(state.1.get_unchecked_manual(cell)) }
+    }
+}
+
 pub trait ReactiveSystemParam: SystemParam {
     type State: Send + Sync + 'static;
 
     fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State;
 
-    fn is_changed(world: DeferredWorld, state: &mut <Self as ReactiveSystemParam>::State) -> bool;
+    /// `last_run` is the tick of this reaction's previous run, so impls can
+    /// check "changed since *this reaction* last ran" instead of relying on
+    /// the world's ambient change tick.
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool;
 
     unsafe fn get<'w: 's, 's>(
         world: &'w mut DeferredWorld<'w>,
@@ -77,9 +162,14 @@ impl ReactiveSystemParam for Commands<'_, '_> {
         let _ = world;
     }
 
-    fn is_changed(world: DeferredWorld, state: &mut <Self as ReactiveSystemParam>::State) -> bool {
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
         let _ = world;
         let _ = state;
+        let _ = last_run;
 
         false
     }
@@ -101,9 +191,17 @@ impl<R: Resource> ReactiveSystemParam for Res<'_, R> {
         let _ = world;
     }
 
-    fn is_changed(world: DeferredWorld, state: &mut <Self as ReactiveSystemParam>::State) -> bool {
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
         let _ = state;
-        world.resource_ref::<R>().is_changed()
+        let this_run = Tick::new(world.change_tick());
+        world
+            .resource_ref::<R>()
+            .last_changed()
+            .is_newer_than(last_run, this_run)
     }
 
     unsafe fn get<'w: 's, 's>(
@@ -115,83 +213,396 @@ impl<R: Resource> ReactiveSystemParam for Res<'_, R> {
     }
 }
 
-impl<D, F> ReactiveSystemParam for Query<'_, '_, D, F>
-where
-    D: ReactiveQueryData<F> + QueryData + 'static,
-    F: QueryFilter + 'static,
-{
-    type State = <D as ReactiveQueryData<F>>::State;
+impl<R: Resource> ReactiveSystemParam for ResMut<'_, R> {
+    type State = ();
 
     fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
-        <D as ReactiveQueryData<F>>::init(world)
+        let _ = world;
     }
 
-    fn is_changed<'a>(
+    fn is_changed(
         world: DeferredWorld,
         state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
     ) -> bool {
-        <D as ReactiveQueryData<F>>::is_changed(world, state)
+        let _ = state;
+        let this_run = Tick::new(world.change_tick());
+        world
+            .resource_ref::<R>()
+            .last_changed()
+            .is_newer_than(last_run, this_run)
     }
 
     unsafe fn get<'w: 's, 's>(
         world: &'w mut DeferredWorld<'w>,
         state: &'s mut <Self as ReactiveSystemParam>::State,
     ) -> Self::Item<'w, 's> {
-        <D as ReactiveQueryData<F>>::get(world, state)
+        let _ = state;
+        world.resource_mut::<R>()
     }
 }
 
-impl<T: ReactiveSystemParam> ReactiveSystemParam for (T,) {
-    type State = <T as ReactiveSystemParam>::State;
+impl<R: Resource> ReactiveSystemParam for Option<Res<'_, R>> {
+    type State = ();
 
     fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
-        T::init(world)
+        let _ = world;
     }
 
-    fn is_changed<'a>(
+    fn is_changed(
         world: DeferredWorld,
         state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
     ) -> bool {
-        T::is_changed(world, state)
+        let _ = state;
+        let this_run = Tick::new(world.change_tick());
+        world
+            .get_resource_ref::<R>()
+            .map(|res| res.last_changed().is_newer_than(last_run, this_run))
+            .unwrap_or(false)
     }
 
     unsafe fn get<'w: 's, 's>(
         world: &'w mut DeferredWorld<'w>,
         state: &'s mut <Self as ReactiveSystemParam>::State,
     ) -> Self::Item<'w, 's> {
-        (T::get(world, state),)
+        let _ = state;
+        world.get_resource_ref::<R>()
     }
 }
 
-impl<T1: ReactiveSystemParam, T2: ReactiveSystemParam> ReactiveSystemParam for (T1, T2) {
-    type State = (
-        <T1 as ReactiveSystemParam>::State,
-        <T2 as ReactiveSystemParam>::State,
-    );
+impl<R: Resource> ReactiveSystemParam for Option<ResMut<'_, R>> {
+    type State = ();
 
     fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
-        (T1::init(world), T2::init(world))
+        let _ = world;
     }
 
-    fn is_changed<'a>(
-        mut world: DeferredWorld,
+    fn is_changed(
+        world: DeferredWorld,
         state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
     ) -> bool {
-        T1::is_changed(world.reborrow(), &mut state.0) || T2::is_changed(world, &mut state.1)
+        let _ = state;
+        let this_run = Tick::new(world.change_tick());
+        world
+            .get_resource_ref::<R>()
+            .map(|res| res.last_changed().is_newer_than(last_run, this_run))
+            .unwrap_or(false)
     }
 
     unsafe fn get<'w: 's, 's>(
         world: &'w mut DeferredWorld<'w>,
         state: &'s mut <Self as ReactiveSystemParam>::State,
     ) -> Self::Item<'w, 's> {
-        let world_ptr = world as *mut _;
-        (
-            T1::get(unsafe { &mut *world_ptr }, &mut state.0),
-            T2::get(unsafe { &mut *world_ptr }, &mut state.1),
-        )
+        let _ = state;
+        world.get_resource_mut::<R>()
     }
 }
 
+// `Local`'s state *is* the value itself (rather than `()`), so it persists
+// across reaction runs instead of being rebuilt from `FromWorld` every time.
+impl<T: FromWorld + Send + Sync + 'static> ReactiveSystemParam for Local<'_, T> {
+    type State = T;
+
+    fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+        T::from_world(world)
+    }
+
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
+        let _ = world;
+        let _ = state;
+        let _ = last_run;
+
+        false
+    }
+
+    unsafe fn get<'w: 's, 's>(
+        world: &'w mut DeferredWorld<'w>,
+        state: &'s mut <Self as ReactiveSystemParam>::State,
+    ) -> Self::Item<'w, 's> {
+        let _ = world;
+        // SAFETY: `Local<'s, T>` is a `#[repr(transparent)]` wrapper around
+        // a single `&'s mut T`, so reinterpreting `state` (already exactly
+        // that reference) as `Local<'s, T>` doesn't change its layout.
+        unsafe { mem::transmute(state) }
+    }
+}
+
+impl<R: 'static> ReactiveSystemParam for NonSend<'_, R> {
+    type State = ();
+
+    fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+        let _ = world;
+    }
+
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
+        let _ = state;
+        let this_run = Tick::new(world.change_tick());
+        world
+            .non_send_resource_ref::<R>()
+            .last_changed()
+            .is_newer_than(last_run, this_run)
+    }
+
+    unsafe fn get<'w: 's, 's>(
+        world: &'w mut DeferredWorld<'w>,
+        state: &'s mut <Self as ReactiveSystemParam>::State,
+    ) -> Self::Item<'w, 's> {
+        let _ = state;
+        world.non_send_resource_ref::<R>()
+    }
+}
+
+impl<R: 'static> ReactiveSystemParam for NonSendMut<'_, R> {
+    type State = ();
+
+    fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+        let _ = world;
+    }
+
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
+        let _ = state;
+        let this_run = Tick::new(world.change_tick());
+        world
+            .non_send_resource_ref::<R>()
+            .last_changed()
+            .is_newer_than(last_run, this_run)
+    }
+
+    unsafe fn get<'w: 's, 's>(
+        world: &'w mut DeferredWorld<'w>,
+        state: &'s mut <Self as ReactiveSystemParam>::State,
+    ) -> Self::Item<'w, 's> {
+        let _ = state;
+        world.non_send_resource_mut::<R>()
+    }
+}
+
+// Unlike the other params, `EventReader`'s state must genuinely persist
+// across reaction runs: its `ManualEventReader` cursor is what lets each
+// reaction drain events independently (and exactly once) rather than all
+// reactions racing over the same `Events<E>` resource.
+impl<E: Event> ReactiveSystemParam for EventReader<'_, '_, E> {
+    // `SystemState<EventReader<'static, 'static, E>>` already owns a
+    // persistent `ManualEventReader<E>` cursor internally (the same way
+    // the `&T` query impl's `SystemState` persists its own change tick),
+    // so `get` below only ever needs to reinterpret the `'w`/`'s`
+    // lifetimes of an already-real `EventReader`, exactly like the `&T`
+    // query impl's transmute does.
+    type State = SystemState<EventReader<'static, 'static, E>>;
+
+    fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+        SystemState::new(world)
+    }
+
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
+        let _ = last_run;
+        !state.get(&world).is_empty()
+    }
+
+    unsafe fn get<'w: 's, 's>(
+        world: &'w mut DeferredWorld<'w>,
+        state: &'s mut <Self as ReactiveSystemParam>::State,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: only reinterprets the `'static` lifetimes `SystemState`
+        // was created with as the real `'w`/`'s` borrows of `world`/`state`
+        // that produced this `EventReader`; the underlying value is
+        // unchanged.
+        unsafe { mem::transmute(state.get(&world)) }
+    }
+}
+
+impl<D, F> ReactiveSystemParam for Query<'_, '_, D, F>
+where
+    D: ReactiveQueryData<F> + QueryData + 'static,
+    F: QueryFilter + 'static,
+{
+    type State = <D as ReactiveQueryData<F>>::State;
+
+    fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+        <D as ReactiveQueryData<F>>::init(world)
+    }
+
+    fn is_changed(
+        world: DeferredWorld,
+        state: &mut <Self as ReactiveSystemParam>::State,
+        last_run: Tick,
+    ) -> bool {
+        <D as ReactiveQueryData<F>>::is_changed(world, state, last_run)
+    }
+
+    unsafe fn get<'w: 's, 's>(
+        world: &'w mut DeferredWorld<'w>,
+        state: &'s mut <Self as ReactiveSystemParam>::State,
+    ) -> Self::Item<'w, 's> {
+        <D as ReactiveQueryData<F>>::get(world, state)
+    }
+}
+
+// Generates `ReactiveSystemParam` for tuples of arity 1 through 16, the
+// same spread Bevy's own `SystemParam` tuple impls cover. Each member's
+// `world` reborrow is folded into this single macro body instead of being
+// hand-copied per arity, so the aliasing pattern only has to be audited
+// once (see `get` below).
+macro_rules! impl_reactive_system_param {
+    ($($param: ident),*) => {
+        impl<$($param: ReactiveSystemParam),*> ReactiveSystemParam for ($($param,)*) {
+            type State = ($($param::State,)*);
+
+            #[allow(non_snake_case, unused_variables)]
+            fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+                // `ReactiveSystemParam: SystemParam` means every member is
+                // already a real Bevy `SystemParam`, so building (and
+                // immediately dropping) a `SystemState` over the whole tuple
+                // runs Bevy's own conflict checker over it first: it panics
+                // on overlapping access (e.g. `(ResMut<Foo>, ResMut<Foo>)`)
+                // exactly the way an ordinary Bevy system would, instead of
+                // letting `get` alias two `&mut` borrows of the same data.
+                // Conflicting members should go through `ReactiveParamSet`
+                // instead. The `SystemState` itself is discarded; the
+                // tuple's actual functional state still comes from each
+                // member's own `ReactiveSystemParam::init` below.
+                let _ = SystemState::<($($param,)*)>::new(world);
+                ($($param::init(world),)*)
+            }
+
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn is_changed(
+                mut world: DeferredWorld,
+                state: &mut <Self as ReactiveSystemParam>::State,
+                last_run: Tick,
+            ) -> bool {
+                let ($($param,)*) = state;
+                false $(|| $param::is_changed(world.reborrow(), $param, last_run))*
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            unsafe fn get<'w: 's, 's>(
+                world: &'w mut DeferredWorld<'w>,
+                state: &'s mut <Self as ReactiveSystemParam>::State,
+            ) -> Self::Item<'w, 's> {
+                // SAFETY: every member gets a `&mut` to the same world, and
+                // the items they return all stay alive together in the
+                // tuple. This relies on `init` having run Bevy's conflict
+                // checker over the tuple, so no two members have
+                // overlapping access. Conflicting members within the *same*
+                // tuple must instead go through `ReactiveParamSet`.
+                let ($($param,)*) = state;
+                let world_ptr = world as *mut _;
+                ($($param::get(unsafe { &mut *world_ptr }, $param),)*)
+            }
+        }
+    };
+}
+
+bevy::utils::all_tuples!(impl_reactive_system_param, 1, 16, P);
+
+/// A reactive param that grants time-sliced access to a group of
+/// [`ReactiveSystemParam`]s that would otherwise conflict with each other,
+/// e.g. two `Query`s that can touch the same archetype. Mirrors Bevy's
+/// `ParamSet`: only one inner param may be borrowed at a time, via its
+/// `pN` accessor, so two conflicting borrows are never alive at once.
+pub struct ReactiveParamSet<'w, 's, T: ReactiveSystemParam> {
+    world: &'w mut DeferredWorld<'w>,
+    state: &'s mut <T as ReactiveSystemParam>::State,
+}
+
+// Generates the `pN` accessors plus the `ReactiveSystemParam`/`SystemParam`
+// impls for a `ReactiveParamSet` of a given arity. Mirrors Bevy's own
+// `impl_param_set!`, including the arity it stops at (8).
+macro_rules! impl_reactive_param_set {
+    ($(($param: ident, $idx: tt)),*) => {
+        bevy::utils::paste! {
+            impl<$($param: ReactiveSystemParam),*> ReactiveParamSet<'_, '_, ($($param,)*)> {
+                $(
+                    /// Borrows this param in the set. The borrow is released
+                    /// when the returned item is dropped, so it never
+                    /// aliases another `pN()` call.
+                    pub fn [<p $idx>](&mut self) -> SystemParamItem<'_, '_, $param> {
+                        // SAFETY: `world` is reborrowed for as long as the
+                        // returned item lives, which keeps `self` mutably
+                        // borrowed, so the set never hands out two `pN`
+                        // items at the same time.
+                        let world: &mut DeferredWorld = unsafe { mem::transmute(&mut *self.world) };
+                        unsafe { $param::get(world, &mut self.state.$idx) }
+                    }
+                )*
+            }
+        }
+
+        impl<$($param: ReactiveSystemParam),*> ReactiveSystemParam for ReactiveParamSet<'_, '_, ($($param,)*)> {
+            type State = ($($param::State,)*);
+
+            fn init(world: &mut World) -> <Self as ReactiveSystemParam>::State {
+                ($($param::init(world),)*)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn is_changed(
+                mut world: DeferredWorld,
+                state: &mut <Self as ReactiveSystemParam>::State,
+                last_run: Tick,
+            ) -> bool {
+                false $(|| $param::is_changed(world.reborrow(), &mut state.$idx, last_run))*
+            }
+
+            unsafe fn get<'w: 's, 's>(
+                world: &'w mut DeferredWorld<'w>,
+                state: &'s mut <Self as ReactiveSystemParam>::State,
+            ) -> Self::Item<'w, 's> {
+                ReactiveParamSet { world, state }
+            }
+        }
+
+        // `ReactiveSystemParam` requires `SystemParam` so that a
+        // `ReactiveParamSet` can appear as an ordinary argument in a
+        // reaction function signature. Bevy's own system-param machinery
+        // (`get_param`) is never invoked by this crate at runtime:
+        // `FunctionReactiveSystem::run` always fetches params through
+        // `ReactiveSystemParam::get` instead. This impl exists purely to
+        // satisfy the type-level bound.
+        unsafe impl<$($param: ReactiveSystemParam),*> SystemParam for ReactiveParamSet<'_, '_, ($($param,)*)> {
+            type State = <Self as ReactiveSystemParam>::State;
+            type Item<'w, 's> = ReactiveParamSet<'w, 's, ($($param,)*)>;
+
+            fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
+                <Self as ReactiveSystemParam>::init(world)
+            }
+
+            unsafe fn get_param<'w, 's>(
+                _state: &'s mut Self::State,
+                _system_meta: &SystemMeta,
+                _world: UnsafeWorldCell<'w>,
+                _change_tick: Tick,
+            ) -> Self::Item<'w, 's> {
+                unreachable!(
+                    "ReactiveParamSet is only ever constructed via ReactiveSystemParam::get; \
+                     Bevy's regular system execution never calls into a reaction"
+                )
+            }
+        }
+    };
+}
+
+bevy::utils::all_tuples_with_size!(impl_reactive_param_set, 1, 8, P, I);
+
 pub struct Scope<T = ()> {
     pub entity: Entity,
     pub input: T,
@@ -256,6 +667,10 @@ pub trait ReactiveSystem: Send + Sync {
 pub struct FunctionReactiveSystem<F, S, Marker> {
     f: F,
     state: Option<S>,
+    /// The world's change tick as of this reaction's previous run, so
+    /// `is_changed` can fire on changes since *this reaction* last ran
+    /// rather than since whenever the world last happened to check.
+    last_run: Tick,
     _marker: PhantomData<Marker>,
 }
 
@@ -271,18 +686,25 @@ where
 
     fn init(&mut self, world: &mut World) {
         self.state = Some(F::Param::init(world));
+        self.last_run = Tick::new(world.change_tick());
     }
 
     fn is_changed(&mut self, world: DeferredWorld) -> bool {
-        F::Param::is_changed(world, self.state.as_mut().unwrap())
+        F::Param::is_changed(world, self.state.as_mut().unwrap(), self.last_run)
     }
 
     fn run(&mut self, input: Self::In, mut world: DeferredWorld, entity: Entity) -> Self::Out {
-        // TODO check for overlapping params
-        let mut world = world.reborrow();
-        let params = unsafe { F::Param::get(&mut world, self.state.as_mut().unwrap()) };
+        let this_run = Tick::new(world.change_tick());
+
+        // Overlapping/conflicting params are handled by wrapping them in a
+        // `ReactiveParamSet`, which only ever exposes one inner param at a
+        // time through its `pN` accessors.
+        let mut reborrowed = world.reborrow();
+        let params = unsafe { F::Param::get(&mut reborrowed, self.state.as_mut().unwrap()) };
 
-        self.f.run(params, input, entity)
+        let out = self.f.run(params, input, entity);
+        self.last_run = this_run;
+        out
     }
 }
 
@@ -319,6 +741,7 @@ impl Reaction {
             system: Arc::new(Mutex::new(Box::new(FunctionReactiveSystem {
                 f: system,
                 state: None,
+                last_run: Tick::new(0),
                 _marker: PhantomData,
             }))),
         }